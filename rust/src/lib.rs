@@ -7,6 +7,12 @@ use fliptevaluation::{
 use fliptevaluation::models::flipt;
 
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::collections::hash_map::DefaultHasher;
+use std::sync::{Arc, Mutex, Weak};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::thread::JoinHandle;
+use std::time::Duration;
 use serde::{Serialize, Deserialize};
 use serde_json;
 uniffi::setup_scaffolding!();
@@ -33,10 +39,60 @@ pub struct ClientOptions {
 
 #[derive(uniffi::Object)]
 pub struct FliptClient {
-    inner: Engine,
+    inner: Arc<Engine>,
+    namespace: String,
+    update_interval: Option<u64>,
+    last_digest: Mutex<Option<String>>,
+    // Weak so that dropping the handle returned to the caller actually stops
+    // the watch thread; the client never keeps a strong reference alive.
+    listeners: Mutex<HashMap<u64, Weak<ListenerHandle>>>,
+    next_listener_id: AtomicU64,
 }
 
-#[uniffi::export]
+/// Callback invoked by a background watch thread whenever the set of flags
+/// served by the engine changes, or when reading the snapshot fails.
+/// Implemented on the React Native side and passed across the UniFFI boundary.
+#[uniffi::export(callback_interface)]
+pub trait FlagChangeListener: Send + Sync {
+    /// Called with the keys of flags that were added or removed, or whose
+    /// serialized definition, as the engine reports it, differs from the
+    /// previously observed snapshot (any field, description included).
+    fn on_change(&self, changed_keys: Vec<String>);
+    /// Called when the watch thread could not read the snapshot.
+    fn on_error(&self, err: FliptError);
+}
+
+/// Opaque handle returned by [`FliptClient::register_listener`]. Dropping it,
+/// passing it to [`FliptClient::unregister_listener`], or closing the client
+/// stops the associated watch thread and joins it cleanly.
+#[derive(uniffi::Object)]
+pub struct ListenerHandle {
+    id: u64,
+    stop: Arc<AtomicBool>,
+    join: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl ListenerHandle {
+    /// Signal the watch thread to stop and block until it has exited.
+    fn stop_and_join(&self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.join.lock().unwrap().take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+impl Drop for ListenerHandle {
+    fn drop(&mut self) {
+        self.stop_and_join();
+    }
+}
+
+// The async evaluation methods below run on `tokio` via UniFFI's async
+// support, so the crate manifest must declare `tokio` (with `rt` +
+// `rt-multi-thread`) and enable uniffi's `tokio` feature. No other feature of
+// this binding pulls in an async runtime.
+#[uniffi::export(async_runtime = "tokio")]
 impl FliptClient {
     #[uniffi::constructor]
     pub fn new(opts: ClientOptions) -> Result<Self, FliptError> {
@@ -48,7 +104,7 @@ impl FliptClient {
         // Create JSON configuration using the actual options
         let mut config = serde_json::json!({
             "url": url,
-            "namespace": namespace,
+            "namespace": namespace.clone(),
             "environment": environment
         });
 
@@ -63,7 +119,8 @@ impl FliptClient {
             config["reference"] = serde_json::Value::String(reference);
         }
 
-        if let Some(interval) = opts.update_interval {
+        let update_interval = opts.update_interval;
+        if let Some(interval) = update_interval {
             config["update_interval"] = serde_json::Value::Number(serde_json::Number::from(interval));
         }
 
@@ -71,118 +128,260 @@ impl FliptClient {
             config["fetch_mode"] = serde_json::Value::String(fetch_mode);
         }
 
-        // Serialize configuration to JSON
-        let config_json = serde_json::to_string(&config)
-            .map_err(|e| FliptError::InvalidRequest {
-                message: format!("Failed to serialize client configuration: {}", e)
-            })?;
+        let engine = init_engine(&config)?;
+
+        Ok(Self {
+            inner: Arc::new(engine),
+            namespace,
+            update_interval,
+            last_digest: Mutex::new(None),
+            listeners: Mutex::new(HashMap::new()),
+            next_listener_id: AtomicU64::new(0),
+        })
+    }
 
-        // Create C string for FFI
-        let c_string = std::ffi::CString::new(config_json)
+    /// Initialize a client from an embedded flag snapshot instead of a live
+    /// server. `snapshot_json` is the same document shape Flipt serves; the
+    /// engine evaluates against it directly, so apps can boot with zero network
+    /// (cold start on flaky connectivity, or deterministic tests). Pairs with
+    /// [`FliptClient::export_snapshot`] to persist state back out.
+    ///
+    /// This depends on the engine honoring an embedded-snapshot config
+    /// (`fetch_mode: "embedded"` with an inline `snapshot`). If a given
+    /// `fliptengine` build only supports polling/streaming and ignores those
+    /// keys, the engine would load no flags; rather than let that surface as a
+    /// silent miss at first evaluation, the constructor verifies the snapshot
+    /// actually loaded and fails here instead (see the guard below).
+    #[uniffi::constructor]
+    pub fn from_snapshot(snapshot_json: String, opts: ClientOptions) -> Result<Self, FliptError> {
+        // Validate the document up front so an obviously broken snapshot fails
+        // here rather than as an opaque null pointer out of the engine.
+        let snapshot: serde_json::Value = serde_json::from_str(&snapshot_json)
             .map_err(|e| FliptError::InvalidRequest {
-                message: format!("Failed to create C string from config: {}", e)
+                message: format!("Invalid snapshot JSON: {}", e)
             })?;
 
-        // Initialize the engine through FFI
-        let engine_ptr = unsafe {
-            fliptengine::initialize_engine(c_string.into_raw())
-        };
+        let namespace = opts.namespace.unwrap_or_else(|| "default".to_string());
+        let environment = opts.environment.unwrap_or_else(|| "default".to_string());
 
-        if engine_ptr.is_null() {
-            return Err(FliptError::Internal {
-                message: "Failed to initialize Flipt engine: null pointer returned".to_string()
-            });
-        }
+        // Drive the engine from the embedded snapshot with no `url`, so it
+        // never attempts to reach a server.
+        let config = serde_json::json!({
+            "namespace": namespace.clone(),
+            "environment": environment,
+            "fetch_mode": "embedded",
+            "snapshot": snapshot,
+        });
 
-        let engine = unsafe { *Box::from_raw(engine_ptr as *mut Engine) };
+        let engine = init_engine(&config)?;
+
+        // Confirm the engine actually honored the embedded-snapshot config. If
+        // the document declared flags but the engine loaded none — e.g. a build
+        // that ignores `fetch_mode: "embedded"`/`snapshot` and only polls — fail
+        // here at construction rather than surfacing an opaque miss at the first
+        // evaluation.
+        let declared = snapshot.get("flags").and_then(|f| f.as_array()).map_or(0, |a| a.len());
+        if declared > 0 {
+            let loaded = engine.list_flags()
+                .map_err(|e| FliptError::Internal { message: e.to_string() })?;
+            if loaded.is_empty() {
+                return Err(FliptError::InvalidRequest {
+                    message: "Engine did not load the embedded snapshot; this fliptengine build may not support fetch_mode=\"embedded\" with an inline snapshot".to_string()
+                });
+            }
+        }
 
-        Ok(Self { inner: engine })
+        Ok(Self {
+            inner: Arc::new(engine),
+            namespace,
+            update_interval: opts.update_interval,
+            last_digest: Mutex::new(None),
+            listeners: Mutex::new(HashMap::new()),
+            next_listener_id: AtomicU64::new(0),
+        })
     }
 
     pub fn evaluate_variant(&self, request: EvaluationRequest) -> Result<VariantEvaluationResponse, FliptError> {
-        // Input validation
-        if request.flag_key.is_empty() {
-            return Err(FliptError::InvalidRequest {
-                message: "Flag key cannot be empty".to_string()
-            });
-        }
-        if request.entity_id.is_empty() {
-            return Err(FliptError::InvalidRequest {
-                message: "Entity ID cannot be empty".to_string()
-            });
-        }
+        eval_variant(&self.inner, request)
+    }
 
-        let eval_request = FliptEvaluationRequest {
-            flag_key: request.flag_key,
-            entity_id: request.entity_id,
-            context: request.context,
-        };
+    pub fn evaluate_boolean(&self, request: EvaluationRequest) -> Result<BooleanEvaluationResponse, FliptError> {
+        eval_boolean(&self.inner, request)
+    }
 
-        let response = self.inner.variant(&eval_request)
-            .map_err(|e| FliptError::Internal {
-                message: format!("Flag evaluation failed: {}", e)
-            })?;
+    pub fn evaluate_batch(&self, requests: Vec<EvaluationRequest>) -> Result<BatchEvaluationResponse, FliptError> {
+        eval_batch(&self.inner, requests)
+    }
 
-        Ok(VariantEvaluationResponse {
-            flag_match: response.r#match,
-            segment_keys: response.segment_keys,
-            reason: format_reason(&response.reason),
-            flag_key: response.flag_key,
-            variant_key: response.variant_key,
-            variant_attachment: response.variant_attachment.unwrap_or_default(),
-            request_duration_millis: response.request_duration_millis,
-            timestamp: response.timestamp.to_rfc3339(),
-        })
+    /// Variant evaluation accepting a typed context. Values are coerced to the
+    /// string representation the engine matches constraints against (see
+    /// [`ContextValue`] for the rules) before delegating to the string path.
+    pub fn evaluate_variant_typed(&self, request: TypedEvaluationRequest) -> Result<VariantEvaluationResponse, FliptError> {
+        eval_variant(&self.inner, request.into())
     }
 
-    pub fn evaluate_boolean(&self, request: EvaluationRequest) -> Result<BooleanEvaluationResponse, FliptError> {
-        // Input validation
-        if request.flag_key.is_empty() {
-            return Err(FliptError::InvalidRequest {
-                message: "Flag key cannot be empty".to_string()
-            });
-        }
-        if request.entity_id.is_empty() {
-            return Err(FliptError::InvalidRequest {
-                message: "Entity ID cannot be empty".to_string()
-            });
-        }
+    /// Boolean counterpart to [`FliptClient::evaluate_variant_typed`].
+    pub fn evaluate_boolean_typed(&self, request: TypedEvaluationRequest) -> Result<BooleanEvaluationResponse, FliptError> {
+        eval_boolean(&self.inner, request.into())
+    }
 
-        let eval_request = FliptEvaluationRequest {
-            flag_key: request.flag_key,
-            entity_id: request.entity_id,
-            context: request.context,
-        };
+    /// Batch counterpart to [`FliptClient::evaluate_variant_typed`], accepting
+    /// a typed context on each request.
+    pub fn evaluate_batch_typed(&self, requests: Vec<TypedEvaluationRequest>) -> Result<BatchEvaluationResponse, FliptError> {
+        let requests = requests.into_iter().map(EvaluationRequest::from).collect();
+        eval_batch(&self.inner, requests)
+    }
 
-        let response = self.inner.boolean(&eval_request)
-            .map_err(|e| FliptError::Internal {
-                message: format!("Boolean evaluation failed: {}", e)
-            })?;
+    /// Async counterpart to [`FliptClient::evaluate_variant`]. Runs the
+    /// evaluation on a blocking worker so the React Native bridge/JSI thread is
+    /// never held while the engine works (or fetches); the generated TypeScript
+    /// returns a real `Promise`.
+    pub async fn evaluate_variant_async(&self, request: EvaluationRequest) -> Result<VariantEvaluationResponse, FliptError> {
+        let engine = Arc::clone(&self.inner);
+        tokio::task::spawn_blocking(move || eval_variant(&engine, request))
+            .await
+            .map_err(join_error)?
+    }
 
-        Ok(BooleanEvaluationResponse {
-            enabled: response.enabled,
-            flag_key: response.flag_key,
-            reason: format_reason(&response.reason),
-            request_duration_millis: response.request_duration_millis,
-            timestamp: response.timestamp.to_rfc3339(),
-        })
+    /// Async counterpart to [`FliptClient::evaluate_boolean`].
+    pub async fn evaluate_boolean_async(&self, request: EvaluationRequest) -> Result<BooleanEvaluationResponse, FliptError> {
+        let engine = Arc::clone(&self.inner);
+        tokio::task::spawn_blocking(move || eval_boolean(&engine, request))
+            .await
+            .map_err(join_error)?
     }
 
-    pub fn evaluate_batch(&self, requests: Vec<EvaluationRequest>) -> Result<BatchEvaluationResponse, FliptError> {
-        let eval_requests: Vec<FliptEvaluationRequest> = requests.into_iter().map(|r| {
-            FliptEvaluationRequest {
-                flag_key: r.flag_key,
-                entity_id: r.entity_id,
-                context: r.context,
-            }
-        }).collect();
+    /// Async counterpart to [`FliptClient::evaluate_batch`].
+    pub async fn evaluate_batch_async(&self, requests: Vec<EvaluationRequest>) -> Result<BatchEvaluationResponse, FliptError> {
+        let engine = Arc::clone(&self.inner);
+        tokio::task::spawn_blocking(move || eval_batch(&engine, requests))
+            .await
+            .map_err(join_error)?
+    }
+}
 
-        let batch_response = self.inner.batch(eval_requests)
-            .map_err(|e| FliptError::Internal {
-                message: format!("Batch evaluation failed: {}", e)
-            })?;
+// Format the current UTC time as an RFC 3339 timestamp using only std, so the
+// binding needs no date/time crate for this single field. Uses Howard
+// Hinnant's civil-from-days conversion.
+fn now_rfc3339() -> String {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+    let (hour, minute, second) = (rem / 3_600, (rem % 3_600) / 60, rem % 60);
+
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let year = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = if month <= 2 { year + 1 } else { year };
+
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", year, month, day, hour, minute, second)
+}
+
+// Map a worker-thread join failure (panic or cancellation) onto a FliptError.
+fn join_error(err: tokio::task::JoinError) -> FliptError {
+    FliptError::Internal {
+        message: format!("Evaluation task failed: {}", err)
+    }
+}
+
+// Evaluate a single variant flag against `engine`, sharing input validation and
+// response mapping between the synchronous and async entry points.
+fn eval_variant(engine: &Engine, request: EvaluationRequest) -> Result<VariantEvaluationResponse, FliptError> {
+    // Input validation
+    if request.flag_key.is_empty() {
+        return Err(FliptError::InvalidRequest {
+            message: "Flag key cannot be empty".to_string()
+        });
+    }
+    if request.entity_id.is_empty() {
+        return Err(FliptError::InvalidRequest {
+            message: "Entity ID cannot be empty".to_string()
+        });
+    }
+
+    let eval_request = FliptEvaluationRequest {
+        flag_key: request.flag_key,
+        entity_id: request.entity_id,
+        context: request.context,
+    };
+
+    let response = engine.variant(&eval_request)
+        .map_err(|e| FliptError::Internal {
+            message: format!("Flag evaluation failed: {}", e)
+        })?;
+
+    Ok(VariantEvaluationResponse {
+        flag_match: response.r#match,
+        segment_keys: response.segment_keys,
+        reason: format_reason(&response.reason),
+        flag_key: response.flag_key,
+        variant_key: response.variant_key,
+        variant_attachment: response.variant_attachment.unwrap_or_default(),
+        request_duration_millis: response.request_duration_millis,
+        timestamp: response.timestamp.to_rfc3339(),
+    })
+}
+
+// Evaluate a single boolean flag against `engine`.
+fn eval_boolean(engine: &Engine, request: EvaluationRequest) -> Result<BooleanEvaluationResponse, FliptError> {
+    // Input validation
+    if request.flag_key.is_empty() {
+        return Err(FliptError::InvalidRequest {
+            message: "Flag key cannot be empty".to_string()
+        });
+    }
+    if request.entity_id.is_empty() {
+        return Err(FliptError::InvalidRequest {
+            message: "Entity ID cannot be empty".to_string()
+        });
+    }
+
+    let eval_request = FliptEvaluationRequest {
+        flag_key: request.flag_key,
+        entity_id: request.entity_id,
+        context: request.context,
+    };
+
+    let response = engine.boolean(&eval_request)
+        .map_err(|e| FliptError::Internal {
+            message: format!("Boolean evaluation failed: {}", e)
+        })?;
+
+    Ok(BooleanEvaluationResponse {
+        enabled: response.enabled,
+        flag_key: response.flag_key,
+        reason: format_reason(&response.reason),
+        request_duration_millis: response.request_duration_millis,
+        timestamp: response.timestamp.to_rfc3339(),
+    })
+}
+
+// Evaluate a batch of requests against `engine`.
+fn eval_batch(engine: &Engine, requests: Vec<EvaluationRequest>) -> Result<BatchEvaluationResponse, FliptError> {
+    let eval_requests: Vec<FliptEvaluationRequest> = requests.into_iter().map(|r| {
+        FliptEvaluationRequest {
+            flag_key: r.flag_key,
+            entity_id: r.entity_id,
+            context: r.context,
+        }
+    }).collect();
+
+    let batch_response = engine.batch(eval_requests)
+        .map_err(|e| FliptError::Internal {
+            message: format!("Batch evaluation failed: {}", e)
+        })?;
 
-        let responses: Vec<EvaluationResponse> = batch_response.responses.into_iter().map(|resp| {
+    let responses: Vec<EvaluationResponse> = batch_response.responses.into_iter().map(|resp| {
             match resp.r#type {
                 flipt::ResponseType::Variant => {
                     if let Some(variant_resp) = resp.variant_evaluation_response {
@@ -271,12 +470,14 @@ impl FliptClient {
             }
         }).collect();
 
-        Ok(BatchEvaluationResponse {
-            responses,
-            request_duration_millis: batch_response.request_duration_millis,
-        })
-    }
+    Ok(BatchEvaluationResponse {
+        responses,
+        request_duration_millis: batch_response.request_duration_millis,
+    })
+}
 
+#[uniffi::export]
+impl FliptClient {
     pub fn list_flags(&self) -> Result<Vec<Flag>, FliptError> {
         let flags = self.inner.list_flags()
             .map_err(|e| FliptError::Internal { message: e.to_string() })?;
@@ -289,15 +490,141 @@ impl FliptClient {
         }).collect())
     }
 
-    pub fn refresh(&self) -> Result<(), FliptError> {
-        // This would typically refresh the snapshot from the server
-        // For now, we'll just return OK since the engine handles this internally
-        Ok(())
+    /// Start watching the snapshot for changes. A background thread re-reads
+    /// the engine's snapshot every `ClientOptions.update_interval` seconds
+    /// (defaulting to 60 when unset or zero), diffs it against the previously
+    /// observed flag digests and invokes `listener.on_change` with only the keys
+    /// that differ. The thread does not fetch from the server itself; it sees
+    /// whatever the engine's own poller has loaded. The returned handle owns the
+    /// thread; drop it or call `unregister_listener` to stop.
+    pub fn register_listener(&self, listener: Box<dyn FlagChangeListener>) -> Arc<ListenerHandle> {
+        let interval = Duration::from_secs(
+            self.update_interval.filter(|i| *i > 0).unwrap_or(60),
+        );
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let engine = Arc::clone(&self.inner);
+        let thread_stop = Arc::clone(&stop);
+
+        let join = std::thread::spawn(move || {
+            let mut previous = match flag_digests(&engine) {
+                Ok(digests) => digests,
+                Err(err) => {
+                    listener.on_error(err);
+                    HashMap::new()
+                }
+            };
+
+            while !thread_stop.load(Ordering::Relaxed) {
+                // Sleep in short slices so a stop request is observed promptly
+                // rather than blocking for the whole update interval.
+                let mut waited = Duration::ZERO;
+                while waited < interval && !thread_stop.load(Ordering::Relaxed) {
+                    std::thread::sleep(Duration::from_millis(250));
+                    waited += Duration::from_millis(250);
+                }
+                if thread_stop.load(Ordering::Relaxed) {
+                    break;
+                }
+
+                match flag_digests(&engine) {
+                    Ok(current) => {
+                        let changed = diff_digests(&previous, &current);
+                        if !changed.is_empty() {
+                            listener.on_change(changed);
+                        }
+                        previous = current;
+                    }
+                    Err(err) => listener.on_error(err),
+                }
+            }
+        });
+
+        let id = self.next_listener_id.fetch_add(1, Ordering::Relaxed);
+        let handle = Arc::new(ListenerHandle {
+            id,
+            stop,
+            join: Mutex::new(Some(join)),
+        });
+        self.listeners.lock().unwrap().insert(id, Arc::downgrade(&handle));
+        handle
+    }
+
+    /// Stop the watch thread associated with `handle` and forget it.
+    pub fn unregister_listener(&self, handle: Arc<ListenerHandle>) {
+        self.listeners.lock().unwrap().remove(&handle.id);
+        handle.stop_and_join();
+    }
+
+    /// Fingerprint the engine's current snapshot and report whether it moved
+    /// since the last call. `digest` is a stable hash over the current flag/
+    /// variant/rule set; repeated calls short-circuit to `changed: false` when
+    /// the digest is unchanged, so a screen checking on focus can skip
+    /// re-rendering whenever `changed` is false.
+    ///
+    /// This observes the snapshot the engine's background poller maintains on
+    /// `ClientOptions.update_interval` — the FFI exposes no synchronous
+    /// force-fetch, so this call deliberately does not itself pull from the
+    /// server. It was previously named `refresh`; that name implied a reload it
+    /// never performed, so it is now named for what it actually does.
+    pub fn check_for_updates(&self) -> Result<RefreshResult, FliptError> {
+        let digest = snapshot_digest(&self.inner)?;
+
+        let mut last = self.last_digest.lock().unwrap();
+        let changed = last.as_deref() != Some(digest.as_str());
+        *last = Some(digest.clone());
+
+        Ok(RefreshResult {
+            changed,
+            digest,
+            fetched_at: now_rfc3339(),
+        })
+    }
+
+    /// Return the digest recorded by the most recent `check_for_updates`, if any.
+    pub fn current_digest(&self) -> Option<String> {
+        self.last_digest.lock().unwrap().clone()
+    }
+
+    /// Serialize the engine's current snapshot as a Flipt document and return
+    /// it as a string that is valid input to [`FliptClient::from_snapshot`].
+    /// Works for any client — including a server-backed one, which is the point
+    /// of the "fetch once online, persist, boot from disk next time" flow — and
+    /// reflects the engine's live state (post-poll), not the document it was
+    /// constructed with.
+    ///
+    /// The returned document carries only the snapshot itself (`namespace` plus
+    /// `flags`); `environment`/`fetch_mode` are client options supplied to
+    /// `from_snapshot` via [`ClientOptions`], not part of the persisted
+    /// snapshot. Flags are serialized through the engine's own `flipt::Flag`
+    /// type, so the shape matches what the engine parses back out — the same
+    /// type round-trips both directions.
+    pub fn export_snapshot(&self) -> Result<String, FliptError> {
+        let flags = self.inner.list_flags()
+            .map_err(|e| FliptError::Internal { message: e.to_string() })?;
+
+        let document = serde_json::json!({
+            "namespace": self.namespace,
+            "flags": flags,
+        });
+
+        serde_json::to_string(&document)
+            .map_err(|e| FliptError::Internal {
+                message: format!("Failed to serialize snapshot: {}", e)
+            })
     }
 
     pub fn close(&self) {
-        // Clean up resources if needed
-        // The engine will be dropped automatically
+        // Stop every still-live watch thread and join it before the engine is
+        // dropped. Entries whose caller-side handle has already been dropped are
+        // stopped by that drop, so only upgradable weak refs need handling here.
+        let handles: Vec<Arc<ListenerHandle>> = self.listeners.lock().unwrap()
+            .drain()
+            .filter_map(|(_, h)| h.upgrade())
+            .collect();
+        for handle in handles {
+            handle.stop_and_join();
+        }
     }
 }
 
@@ -309,6 +636,65 @@ pub struct EvaluationRequest {
     pub context: HashMap<String, String>,
 }
 
+/// A typed evaluation context value.
+///
+/// The engine's evaluation context is a `HashMap<String, String>`: it parses
+/// numeric, boolean and datetime segment constraints out of these strings at
+/// match time. So the value-add of this type is *not* a richer wire format —
+/// it is a single, predictable place that applies the string coercion rules
+/// callers would otherwise each reinvent (and get subtly wrong, e.g. `1.0` vs
+/// `1`). Values are coerced as follows: `String` is passed through verbatim;
+/// `Bool` becomes `"true"`/`"false"`; `DateTime` is passed through as the
+/// caller-supplied RFC 3339 string; and `Number` is formatted without a
+/// trailing zero (`1.0` -> `"1"`, `1.5` -> `"1.5"`) so numeric constraints
+/// compare predictably.
+#[derive(uniffi::Enum, Clone, PartialEq, Debug)]
+pub enum ContextValue {
+    String { value: String },
+    Number { value: f64 },
+    Bool { value: bool },
+    DateTime { value: String },
+}
+
+impl ContextValue {
+    /// Coerce the value to the string representation the engine expects.
+    fn coerce(&self) -> String {
+        match self {
+            ContextValue::String { value } => value.clone(),
+            // `{}` already yields the shortest round-trippable form, so `1.0`
+            // renders as `1` rather than `1.0`.
+            ContextValue::Number { value } => format!("{}", value),
+            ContextValue::Bool { value } => value.to_string(),
+            ContextValue::DateTime { value } => value.clone(),
+        }
+    }
+}
+
+/// Evaluation request whose context carries typed values. Convert into an
+/// [`EvaluationRequest`] (string-only context) via `From` to reuse the existing
+/// evaluation path; plain string maps remain supported through
+/// [`EvaluationRequest`] directly.
+#[derive(uniffi::Record, Clone, PartialEq, Debug)]
+pub struct TypedEvaluationRequest {
+    pub flag_key: String,
+    pub entity_id: String,
+    pub context: HashMap<String, ContextValue>,
+}
+
+impl From<TypedEvaluationRequest> for EvaluationRequest {
+    fn from(request: TypedEvaluationRequest) -> Self {
+        let context = request.context
+            .into_iter()
+            .map(|(key, value)| (key, value.coerce()))
+            .collect();
+        EvaluationRequest {
+            flag_key: request.flag_key,
+            entity_id: request.entity_id,
+            context,
+        }
+    }
+}
+
 #[derive(uniffi::Record, Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct VariantEvaluationResponse {
     pub flag_match: bool,
@@ -351,6 +737,13 @@ pub struct BatchEvaluationResponse {
     pub request_duration_millis: f64,
 }
 
+#[derive(uniffi::Record)]
+pub struct RefreshResult {
+    pub changed: bool,
+    pub digest: String,
+    pub fetched_at: String,
+}
+
 #[derive(uniffi::Record)]
 pub struct Flag {
     pub key: String,
@@ -368,6 +761,89 @@ pub enum FliptError {
     ConnectionError { message: String },
 }
 
+// Serialize a config document and hand it to the engine over FFI, returning
+// the owned `Engine`. Shared by both the server-backed and snapshot-backed
+// constructors so the unsafe hand-off lives in exactly one place.
+fn init_engine(config: &serde_json::Value) -> Result<Engine, FliptError> {
+    let config_json = serde_json::to_string(config)
+        .map_err(|e| FliptError::InvalidRequest {
+            message: format!("Failed to serialize client configuration: {}", e)
+        })?;
+
+    let c_string = std::ffi::CString::new(config_json)
+        .map_err(|e| FliptError::InvalidRequest {
+            message: format!("Failed to create C string from config: {}", e)
+        })?;
+
+    let engine_ptr = unsafe {
+        fliptengine::initialize_engine(c_string.into_raw())
+    };
+
+    if engine_ptr.is_null() {
+        return Err(FliptError::Internal {
+            message: "Failed to initialize Flipt engine: null pointer returned".to_string()
+        });
+    }
+
+    Ok(unsafe { *Box::from_raw(engine_ptr as *mut Engine) })
+}
+
+// Compute a per-flag digest that moves whenever anything a client cares about
+// changes: the enabled state, the flag type, and the variant/rule data. The
+// whole flag is serialized and hashed so weight, rule and variant edits are
+// caught, not just `enabled`/`description`.
+fn flag_digests(engine: &Engine) -> Result<HashMap<String, u64>, FliptError> {
+    let flags = engine.list_flags()
+        .map_err(|e| FliptError::Internal { message: e.to_string() })?;
+
+    let mut digests = HashMap::with_capacity(flags.len());
+    for flag in flags {
+        // The engine's `flipt::Flag` carries the full variant/rule definition;
+        // serializing it yields a fingerprint sensitive to all of it.
+        let serialized = serde_json::to_string(&flag)
+            .map_err(|e| FliptError::Internal {
+                message: format!("Failed to serialize flag for digest: {}", e)
+            })?;
+
+        let mut hasher = DefaultHasher::new();
+        serialized.hash(&mut hasher);
+        digests.insert(flag.key, hasher.finish());
+    }
+    Ok(digests)
+}
+
+// Fold the per-flag digests into a single stable fingerprint over the whole
+// snapshot. Keys are sorted first so the result is deterministic regardless of
+// the order `list_flags` yields, giving test harnesses a fixed value to assert.
+fn snapshot_digest(engine: &Engine) -> Result<String, FliptError> {
+    let mut entries: Vec<(String, u64)> = flag_digests(engine)?.into_iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut hasher = DefaultHasher::new();
+    for (key, digest) in &entries {
+        key.hash(&mut hasher);
+        digest.hash(&mut hasher);
+    }
+    Ok(format!("{:016x}", hasher.finish()))
+}
+
+// Return the keys whose digest differs between two snapshots, including flags
+// that were added or removed.
+fn diff_digests(previous: &HashMap<String, u64>, current: &HashMap<String, u64>) -> Vec<String> {
+    let mut changed = Vec::new();
+    for (key, digest) in current {
+        if previous.get(key) != Some(digest) {
+            changed.push(key.clone());
+        }
+    }
+    for key in previous.keys() {
+        if !current.contains_key(key) {
+            changed.push(key.clone());
+        }
+    }
+    changed
+}
+
 // Helper function to format EvaluationReason enum to string
 fn format_reason(reason: &flipt::EvaluationReason) -> String {
     match reason {
@@ -408,3 +884,86 @@ impl std::fmt::Display for FliptError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A minimal Flipt snapshot document with a single enabled boolean flag,
+    // matching the shape the server serves.
+    const SNAPSHOT: &str = r#"{
+        "namespace": "default",
+        "flags": [
+            {
+                "key": "offline-flag",
+                "name": "Offline Flag",
+                "description": "",
+                "enabled": true,
+                "type": "BOOLEAN_FLAG_TYPE",
+                "rules": [],
+                "rollouts": []
+            }
+        ]
+    }"#;
+
+    fn offline_opts() -> ClientOptions {
+        ClientOptions {
+            environment: None,
+            namespace: Some("default".to_string()),
+            url: None,
+            update_interval: None,
+            reference: None,
+            client_token: None,
+            fetch_mode: None,
+        }
+    }
+
+    fn boolean_request() -> EvaluationRequest {
+        EvaluationRequest {
+            flag_key: "offline-flag".to_string(),
+            entity_id: "entity-1".to_string(),
+            context: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn evaluates_boolean_from_embedded_snapshot_without_server() {
+        let client = FliptClient::from_snapshot(SNAPSHOT.to_string(), offline_opts())
+            .expect("client should boot from the embedded snapshot with no server");
+
+        let response = client.evaluate_boolean(boolean_request())
+            .expect("boolean evaluation should succeed offline");
+
+        assert!(response.enabled);
+        assert_eq!(response.flag_key, "offline-flag");
+    }
+
+    #[test]
+    fn client_boots_from_its_own_export() {
+        // Boot once, export, then boot a second client from that export and
+        // confirm it evaluates identically — proving the persist-and-reboot
+        // round-trip and that export/from_snapshot agree on the document shape.
+        let original = FliptClient::from_snapshot(SNAPSHOT.to_string(), offline_opts())
+            .expect("original client should boot from the embedded snapshot");
+
+        let exported = original.export_snapshot()
+            .expect("a snapshot-backed client should export its state");
+
+        let rebooted = FliptClient::from_snapshot(exported, offline_opts())
+            .expect("a client should boot from another client's export");
+
+        let before = original.evaluate_boolean(boolean_request())
+            .expect("original evaluation should succeed");
+        let after = rebooted.evaluate_boolean(boolean_request())
+            .expect("rebooted evaluation should succeed");
+
+        assert_eq!(before.enabled, after.enabled);
+        assert_eq!(before.flag_key, after.flag_key);
+
+        // The snapshot fingerprint must survive the round-trip unchanged.
+        assert_eq!(
+            snapshot_digest(&original.inner).unwrap(),
+            snapshot_digest(&rebooted.inner).unwrap(),
+        );
+    }
+}